@@ -1,7 +1,7 @@
 use std::borrow::ToOwned;
 use std::collections::HashMap;
 use std::iter::{IntoIterator,FromIterator};
-use std::{slice,vec};
+use std::{iter,slice,vec};
 
 use document::QName;
 use document::dom4;
@@ -138,41 +138,59 @@ impl<'d> Node<'d> {
     }
 
     pub fn children(&self) -> Vec<Node<'d>> {
+        self.children_iter().collect()
+    }
+
+    /// Iterates over the children of this node without allocating,
+    /// in document order.
+    pub fn children_iter(&self) -> AxisIter<'d> {
         use self::Node::*;
         match *self {
-            Root(n)                  => n.children().iter().map(|&n| n.into()).collect(),
-            Element(n)               => n.children().iter().map(|&n| n.into()).collect(),
-            Attribute(_)             => Vec::new(),
-            Text(_)                  => Vec::new(),
-            Comment(_)               => Vec::new(),
-            ProcessingInstruction(_) => Vec::new(),
-            Namespace(_)             => Vec::new(),
+            Root(n)                  => AxisIter::root_children(n.children()),
+            Element(n)               => AxisIter::element_children(n.children()),
+            Attribute(_)             => AxisIter::empty(),
+            Text(_)                  => AxisIter::empty(),
+            Comment(_)               => AxisIter::empty(),
+            ProcessingInstruction(_) => AxisIter::empty(),
+            Namespace(_)             => AxisIter::empty(),
         }
     }
 
     pub fn preceding_siblings(&self) -> Vec<Node<'d>> {
+        self.preceding_siblings_iter().collect()
+    }
+
+    /// Iterates over the siblings preceding this node, nearest first,
+    /// without allocating.
+    pub fn preceding_siblings_iter(&self) -> AxisIter<'d> {
         use self::Node::*;
         match *self {
-            Root(_)                  => Vec::new(),
-            Element(n)               => n.preceding_siblings().iter().rev().map(|&n| n.into()).collect(),
-            Attribute(_)             => Vec::new(),
-            Text(n)                  => n.preceding_siblings().iter().rev().map(|&n| n.into()).collect(),
-            Comment(n)               => n.preceding_siblings().iter().rev().map(|&n| n.into()).collect(),
-            ProcessingInstruction(n) => n.preceding_siblings().iter().rev().map(|&n| n.into()).collect(),
-            Namespace(_)             => Vec::new(),
+            Root(_)                  => AxisIter::empty(),
+            Element(n)               => AxisIter::siblings_rev(n.preceding_siblings()),
+            Attribute(_)             => AxisIter::empty(),
+            Text(n)                  => AxisIter::siblings_rev(n.preceding_siblings()),
+            Comment(n)               => AxisIter::siblings_rev(n.preceding_siblings()),
+            ProcessingInstruction(n) => AxisIter::siblings_rev(n.preceding_siblings()),
+            Namespace(_)             => AxisIter::empty(),
         }
     }
 
     pub fn following_siblings(&self) -> Vec<Node<'d>> {
+        self.following_siblings_iter().collect()
+    }
+
+    /// Iterates over the siblings following this node, nearest first,
+    /// without allocating.
+    pub fn following_siblings_iter(&self) -> AxisIter<'d> {
         use self::Node::*;
         match *self {
-            Root(_)                  => Vec::new(),
-            Element(n)               => n.following_siblings().iter().map(|&n| n.into()).collect(),
-            Attribute(_)             => Vec::new(),
-            Text(n)                  => n.following_siblings().iter().map(|&n| n.into()).collect(),
-            Comment(n)               => n.following_siblings().iter().map(|&n| n.into()).collect(),
-            ProcessingInstruction(n) => n.following_siblings().iter().map(|&n| n.into()).collect(),
-            Namespace(_)             => Vec::new(),
+            Root(_)                  => AxisIter::empty(),
+            Element(n)               => AxisIter::siblings(n.following_siblings()),
+            Attribute(_)             => AxisIter::empty(),
+            Text(n)                  => AxisIter::siblings(n.following_siblings()),
+            Comment(n)               => AxisIter::siblings(n.following_siblings()),
+            ProcessingInstruction(n) => AxisIter::siblings(n.following_siblings()),
+            Namespace(_)             => AxisIter::empty(),
         }
     }
 
@@ -180,10 +198,10 @@ impl<'d> Node<'d> {
         use self::Node::*;
 
         fn document_order_text_nodes(node: &Node, result: &mut String) {
-            for child in node.children().iter() {
+            for child in node.children_iter() {
                 match child {
-                    &Node::Element(_) => document_order_text_nodes(child, result),
-                    &Node::Text(n) => result.push_str(n.text()),
+                    Node::Element(_) => document_order_text_nodes(&child, result),
+                    Node::Text(n) => result.push_str(n.text()),
                     _ => {},
                 }
             }
@@ -249,6 +267,71 @@ impl<'d> Into<Node<'d>> for dom4::ParentOfChild<'d> {
     }
 }
 
+enum AxisIterKind<'d> {
+    RootChildren(slice::Iter<'d, dom4::ChildOfRoot<'d>>),
+    ElementChildren(slice::Iter<'d, dom4::ChildOfElement<'d>>),
+    Siblings(slice::Iter<'d, dom4::ChildOfElement<'d>>),
+    SiblingsRev(iter::Rev<slice::Iter<'d, dom4::ChildOfElement<'d>>>),
+    Empty,
+}
+
+/// A lazy, non-allocating iterator over one of the axes of a `Node`
+/// (its children or its siblings). Borrows directly from the document,
+/// converting each underlying `dom4` node to a `Node` on demand.
+pub struct AxisIter<'d> {
+    inner: AxisIterKind<'d>,
+}
+
+impl<'d> AxisIter<'d> {
+    fn root_children(children: &'d [dom4::ChildOfRoot<'d>]) -> AxisIter<'d> {
+        AxisIter { inner: AxisIterKind::RootChildren(children.iter()) }
+    }
+
+    fn element_children(children: &'d [dom4::ChildOfElement<'d>]) -> AxisIter<'d> {
+        AxisIter { inner: AxisIterKind::ElementChildren(children.iter()) }
+    }
+
+    fn siblings(siblings: &'d [dom4::ChildOfElement<'d>]) -> AxisIter<'d> {
+        AxisIter { inner: AxisIterKind::Siblings(siblings.iter()) }
+    }
+
+    fn siblings_rev(siblings: &'d [dom4::ChildOfElement<'d>]) -> AxisIter<'d> {
+        AxisIter { inner: AxisIterKind::SiblingsRev(siblings.iter().rev()) }
+    }
+
+    fn empty() -> AxisIter<'d> {
+        AxisIter { inner: AxisIterKind::Empty }
+    }
+}
+
+impl<'d> Iterator for AxisIter<'d> {
+    type Item = Node<'d>;
+
+    fn next(&mut self) -> Option<Node<'d>> {
+        use self::AxisIterKind::*;
+        match self.inner {
+            RootChildren(ref mut it)    => it.next().map(|&n| n.into()),
+            ElementChildren(ref mut it) => it.next().map(|&n| n.into()),
+            Siblings(ref mut it)        => it.next().map(|&n| n.into()),
+            SiblingsRev(ref mut it)     => it.next().map(|&n| n.into()),
+            Empty                       => None,
+        }
+    }
+}
+
+impl<'d> DoubleEndedIterator for AxisIter<'d> {
+    fn next_back(&mut self) -> Option<Node<'d>> {
+        use self::AxisIterKind::*;
+        match self.inner {
+            RootChildren(ref mut it)    => it.next_back().map(|&n| n.into()),
+            ElementChildren(ref mut it) => it.next_back().map(|&n| n.into()),
+            Siblings(ref mut it)        => it.next_back().map(|&n| n.into()),
+            SiblingsRev(ref mut it)     => it.next_back().map(|&n| n.into()),
+            Empty                       => None,
+        }
+    }
+}
+
 /// A collection of nodes
 #[derive(PartialEq,Debug,Clone)]
 pub struct Nodeset<'d> {
@@ -299,9 +382,8 @@ impl<'d> Nodeset<'d> {
         while let Some(n) = stack.pop() {
             order.insert(n, idx);
             idx += 1;
-            let c = n.children();
 
-            stack.extend(c.into_iter().rev());
+            stack.extend(n.children_iter().rev());
 
             if let Node::Element(e) = n {
                 // TODO: namespaces
@@ -597,4 +679,94 @@ mod test {
         let text: Node = doc.create_text("hello world").into();
         assert_eq!("hello world", text.string_value());
     }
+
+    #[test]
+    fn following_siblings_are_returned_nearest_first() {
+        let package = Package::new();
+        let doc = package.as_document();
+
+        let parent = doc.create_element("parent");
+        let a = doc.create_element("a");
+        let b = doc.create_element("b");
+        let c = doc.create_element("c");
+
+        parent.append_child(a);
+        parent.append_child(b);
+        parent.append_child(c);
+
+        let node: Node = a.into();
+        let following: Vec<_> = node.following_siblings_iter().collect();
+
+        assert_eq!(following, vec![into_node(b), into_node(c)]);
+    }
+
+    #[test]
+    fn preceding_siblings_are_returned_nearest_first() {
+        let package = Package::new();
+        let doc = package.as_document();
+
+        let parent = doc.create_element("parent");
+        let a = doc.create_element("a");
+        let b = doc.create_element("b");
+        let c = doc.create_element("c");
+
+        parent.append_child(a);
+        parent.append_child(b);
+        parent.append_child(c);
+
+        let node: Node = c.into();
+        let preceding: Vec<_> = node.preceding_siblings_iter().collect();
+
+        assert_eq!(preceding, vec![into_node(b), into_node(a)]);
+    }
+
+    #[test]
+    fn children_iter_supports_reverse_iteration() {
+        let package = Package::new();
+        let doc = package.as_document();
+
+        let parent = doc.create_element("parent");
+        let a = doc.create_element("a");
+        let b = doc.create_element("b");
+
+        parent.append_child(a);
+        parent.append_child(b);
+
+        let node: Node = parent.into();
+        let reversed: Vec<_> = node.children_iter().rev().collect();
+
+        assert_eq!(reversed, vec![into_node(b), into_node(a)]);
+    }
+
+    #[test]
+    fn children_and_children_iter_agree() {
+        let package = Package::new();
+        let doc = package.as_document();
+
+        let parent = doc.create_element("parent");
+        let a = doc.create_element("a");
+        let b = doc.create_element("b");
+
+        parent.append_child(a);
+        parent.append_child(b);
+
+        let node: Node = parent.into();
+        let via_vec = node.children();
+        let via_iter: Vec<_> = node.children_iter().collect();
+
+        assert_eq!(via_vec, via_iter);
+    }
+
+    #[test]
+    fn axis_iterators_are_empty_for_node_kinds_without_that_axis() {
+        let package = Package::new();
+        let doc = package.as_document();
+
+        let element = doc.create_element("element");
+        let attribute: Node = element.set_attribute_value("name", "value").into();
+
+        assert_eq!(Vec::<Node>::new(), attribute.children());
+        assert_eq!(Vec::<Node>::new(), attribute.preceding_siblings());
+        assert_eq!(Vec::<Node>::new(), attribute.following_siblings());
+    }
 }